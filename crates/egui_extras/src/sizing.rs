@@ -1,16 +1,52 @@
 use egui::Rangef;
 
+/// Discrete growth priority controlling which cells absorb leftover space first.
+///
+/// When there is leftover space to distribute, only the cells at the single highest
+/// [`Stretch`] tier present grow to fill it (split between them by the same
+/// equal/weighted rule used for [`Size::Remainder`]); cells at lower tiers keep their
+/// base size. A cell at [`Self::None`] never grows past its base size, even if it's a
+/// [`Size::Remainder`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stretch {
+    /// Never grows past its base size.
+    #[default]
+    None,
+
+    /// Grows if no cell has a higher tier.
+    Low,
+
+    /// Grows if no cell is [`Self::Maximize`].
+    High,
+
+    /// Always wins over any lower tier: while any cell is `Maximize`, `High` and `Low`
+    /// cells don't grow.
+    Maximize,
+}
+
 /// Size hint for table column/strip cell.
 #[derive(Clone, Debug, Copy)]
 pub enum Size {
     /// Absolute size in points, with a given range of allowed sizes to resize within.
-    Absolute { initial: f32, range: Rangef },
+    Absolute {
+        initial: f32,
+        range: Rangef,
+        stretch: Stretch,
+    },
 
     /// Relative size relative to all available space.
-    Relative { fraction: f32, range: Rangef },
+    Relative {
+        fraction: f32,
+        range: Rangef,
+        stretch: Stretch,
+    },
 
-    /// Multiple remainders each get the same space.
-    Remainder { range: Rangef },
+    /// Multiple remainders each get the same space, unless a non-default weight is given.
+    Remainder {
+        range: Rangef,
+        weight: f32,
+        stretch: Stretch,
+    },
 }
 
 impl Size {
@@ -19,6 +55,7 @@ impl Size {
         Self::Absolute {
             initial: points,
             range: Rangef::new(points, points),
+            stretch: Stretch::None,
         }
     }
 
@@ -27,6 +64,7 @@ impl Size {
         Self::Absolute {
             initial: points,
             range: Rangef::new(0.0, f32::INFINITY),
+            stretch: Stretch::None,
         }
     }
 
@@ -39,6 +77,7 @@ impl Size {
         Self::Relative {
             fraction,
             range: Rangef::new(0.0, f32::INFINITY),
+            stretch: Stretch::None,
         }
     }
 
@@ -46,6 +85,18 @@ impl Size {
     pub fn remainder() -> Self {
         Self::Remainder {
             range: Rangef::new(0.0, f32::INFINITY),
+            weight: 1.0,
+            stretch: Stretch::Low,
+        }
+    }
+
+    /// Multiple remainders share the leftover space proportionally to their weight:
+    /// a remainder with weight `2.0` gets twice the leftover space of one with weight `1.0`.
+    pub fn remainder_weighted(weight: f32) -> Self {
+        Self::Remainder {
+            range: Rangef::new(0.0, f32::INFINITY),
+            weight,
+            stretch: Stretch::Low,
         }
     }
 
@@ -69,6 +120,13 @@ impl Size {
         self
     }
 
+    /// Sets the growth-priority tier for this cell, see [`Stretch`].
+    #[inline]
+    pub fn with_stretch(mut self, stretch: Stretch) -> Self {
+        *self.stretch_mut() = stretch;
+        self
+    }
+
     /// Allowed range of movement (in points), if in a resizable [`Table`](crate::table::Table).
     pub fn range(self) -> Rangef {
         match self {
@@ -86,6 +144,31 @@ impl Size {
         }
     }
 
+    /// The stretch weight of a [`Size::Remainder`], or `1.0` for any other variant.
+    pub fn weight(self) -> f32 {
+        match self {
+            Self::Remainder { weight, .. } => weight,
+            Self::Absolute { .. } | Self::Relative { .. } => 1.0,
+        }
+    }
+
+    /// The growth-priority tier of this cell, see [`Stretch`].
+    pub fn stretch(self) -> Stretch {
+        match self {
+            Self::Absolute { stretch, .. }
+            | Self::Relative { stretch, .. }
+            | Self::Remainder { stretch, .. } => stretch,
+        }
+    }
+
+    pub fn stretch_mut(&mut self) -> &mut Stretch {
+        match self {
+            Self::Absolute { stretch, .. }
+            | Self::Relative { stretch, .. }
+            | Self::Remainder { stretch, .. } => stretch,
+        }
+    }
+
     #[inline]
     pub fn is_absolute(&self) -> bool {
         matches!(self, Self::Absolute { .. })
@@ -102,6 +185,20 @@ impl Size {
     }
 }
 
+/// The result of [`Sizing::to_lengths_checked`].
+#[derive(Clone, Debug, Default)]
+pub struct SizingOutput {
+    /// The resolved length of each cell, in the same order they were added in.
+    pub lengths: Vec<f32>,
+
+    /// How much the resolved lengths plus spacing exceed `length`. Zero if everything fit.
+    ///
+    /// Cells are only shrunk towards their `range.min` when the non-remainder cells
+    /// alone don't fit; space claimed by a remainder's `range.min` is not taken back
+    /// from other cells, so it can show up here even if they could still shrink.
+    pub overflow: f32,
+}
+
 #[derive(Clone, Default)]
 pub struct Sizing {
     pub(crate) sizes: Vec<Size>,
@@ -113,59 +210,172 @@ impl Sizing {
     }
 
     pub fn to_lengths(&self, length: f32, spacing: f32) -> Vec<f32> {
+        self.to_lengths_checked(length, spacing).lengths
+    }
+
+    /// Like [`Self::to_lengths`], but also reports how far the resolved layout
+    /// overflows `length` (see [`SizingOutput::overflow`]).
+    ///
+    /// Callers (e.g. `Table`/`Strip`) can use a non-zero `overflow` to decide whether
+    /// to enable horizontal scrolling instead of silently clipping.
+    pub fn to_lengths_checked(&self, length: f32, spacing: f32) -> SizingOutput {
         if self.sizes.is_empty() {
-            return vec![];
+            return SizingOutput::default();
         }
 
-        let mut num_remainders = 0;
-        let sum_non_remainder = self
+        let mut lengths: Vec<f32> = self
             .sizes
             .iter()
             .map(|&size| match size {
                 Size::Absolute { initial, .. } => initial,
-                Size::Relative { fraction, range } => {
+                Size::Relative { fraction, range, .. } => {
                     assert!(
                         0.0 <= fraction && fraction <= 1.0,
                         "fraction should be in the range [0, 1], but was {fraction}"
                     );
                     range.clamp(length * fraction)
                 }
-                Size::Remainder { .. } => {
-                    num_remainders += 1;
-                    0.0
-                }
+                Size::Remainder { .. } => 0.0,
             })
-            .sum::<f32>()
-            + spacing * (self.sizes.len() - 1) as f32;
+            .collect();
 
-        let avg_remainder_length = if num_remainders == 0 {
-            0.0
-        } else {
-            let mut remainder_length = length - sum_non_remainder;
-            let avg_remainder_length = 0.0f32.max(remainder_length / num_remainders as f32).floor();
-            for &size in &self.sizes {
-                if let Size::Remainder { range } = size {
-                    if avg_remainder_length < range.min {
-                        remainder_length -= range.min;
-                        num_remainders -= 1;
+        let sum_non_remainder =
+            lengths.iter().sum::<f32>() + spacing * (self.sizes.len() - 1) as f32;
+
+        let deficit = sum_non_remainder - length;
+        if deficit > 0.0 {
+            // There isn't even enough room for the non-remainder cells at their
+            // current size: shrink each one towards its `range.min`, distributed by
+            // how much slack it has, instead of letting the layout overflow.
+            let slacks: Vec<f32> = self
+                .sizes
+                .iter()
+                .zip(&lengths)
+                .map(|(&size, &current)| {
+                    if size.is_remainder() {
+                        0.0
+                    } else {
+                        (current - size.range().min).max(0.0)
                     }
+                })
+                .collect();
+            let sum_slack = slacks.iter().sum::<f32>();
+
+            if sum_slack >= deficit {
+                for (current, slack) in lengths.iter_mut().zip(&slacks) {
+                    *current -= deficit * slack / sum_slack;
                 }
-            }
-            if num_remainders > 0 {
-                0.0f32.max(remainder_length / num_remainders as f32)
             } else {
-                0.0
+                for (current, slack) in lengths.iter_mut().zip(&slacks) {
+                    *current -= slack;
+                }
             }
-        };
 
-        self.sizes
-            .iter()
-            .map(|&size| match size {
-                Size::Absolute { initial, .. } => initial,
-                Size::Relative { fraction, range } => range.clamp(length * fraction),
-                Size::Remainder { range } => range.clamp(avg_remainder_length),
-            })
-            .collect()
+            // A remainder has no slack of its own to give, but it still keeps its own
+            // `range.min`: that floor was never part of the budget above, so whatever
+            // space it claims here is reported back as overflow (see below) rather
+            // than being silently collapsed to zero.
+            for (current, &size) in lengths.iter_mut().zip(&self.sizes) {
+                if size.is_remainder() {
+                    *current = size.range().min;
+                }
+            }
+        } else {
+            // Leftover space only goes to the cells at the single highest `Stretch`
+            // tier present; cells at lower tiers (including `Stretch::None`) keep
+            // their base size. Within that tier, the surplus is shared by weight (so
+            // remainders of equal weight get equal shares).
+            let highest_tier = self.sizes.iter().map(|size| size.stretch()).max();
+            let in_growth_tier = |size: Size| {
+                highest_tier == Some(size.stretch()) && size.stretch() > Stretch::None
+            };
+
+            // Cells outside the growth tier don't grow, but they must still respect
+            // their own `range.min` (this matters for `Size::Remainder`, whose base
+            // is always `0.0` from the first pass above). Any extra space such a
+            // floor reserves beyond the cell's raw base is taken out of the pool
+            // available to the growth tier.
+            let mut extra_reserved = 0.0;
+            for (&size, base) in self.sizes.iter().zip(lengths.iter_mut()) {
+                if !in_growth_tier(size) {
+                    let floored = size.range().clamp(*base);
+                    extra_reserved += floored - *base;
+                    *base = floored;
+                }
+            }
+
+            if let Some(highest_tier) = highest_tier.filter(|&tier| tier > Stretch::None) {
+                // A cell whose weighted share would push it outside its `range` is
+                // pinned at the bound instead, and its space and weight are removed
+                // from the pool before the rest is (re-)divided. This is repeated
+                // until a full pass pins nothing (standard iterative water-filling):
+                // a single recompute isn't enough once 3+ cells in the same tier have
+                // staggered bounds, since pinning one cell can push the average high
+                // (or low) enough to newly violate another's bound.
+                let mut active: Vec<usize> = self
+                    .sizes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &size)| size.stretch() == highest_tier)
+                    .map(|(i, _)| i)
+                    .collect();
+                let mut surplus = length - sum_non_remainder - extra_reserved;
+                let mut weight_sum: f32 = active.iter().map(|&i| self.sizes[i].weight()).sum();
+
+                loop {
+                    if weight_sum <= 0.0 {
+                        break;
+                    }
+                    let avg_per_weight = 0.0f32.max(surplus / weight_sum);
+                    let mut pinned_any = false;
+                    active.retain(|&i| {
+                        let size = self.sizes[i];
+                        let share = lengths[i] + avg_per_weight * size.weight();
+                        let bound = if share < size.range().min {
+                            Some(size.range().min)
+                        } else if share > size.range().max {
+                            Some(size.range().max)
+                        } else {
+                            None
+                        };
+                        match bound {
+                            Some(bound) => {
+                                surplus -= bound - lengths[i];
+                                weight_sum -= size.weight();
+                                lengths[i] = bound;
+                                pinned_any = true;
+                                false
+                            }
+                            None => true,
+                        }
+                    });
+                    if !pinned_any {
+                        break;
+                    }
+                }
+
+                let avg_growth_per_weight = if weight_sum > 0.0 {
+                    0.0f32.max(surplus / weight_sum)
+                } else {
+                    0.0
+                };
+                for &i in &active {
+                    let size = self.sizes[i];
+                    lengths[i] = size
+                        .range()
+                        .clamp(lengths[i] + avg_growth_per_weight * size.weight());
+                }
+            }
+        }
+
+        // Derive `overflow` from what the layout actually adds up to, rather than
+        // trusting either branch above to have gotten it right: any shortfall in the
+        // deficit branch, or an unmet `range.min` that branch had to grant anyway,
+        // shows up here regardless of which path produced it.
+        let spacing_total = spacing * (self.sizes.len() - 1) as f32;
+        let overflow = (lengths.iter().sum::<f32>() + spacing_total - length).max(0.0);
+
+        SizingOutput { lengths, overflow }
     }
 }
 
@@ -196,3 +406,130 @@ fn test_sizing() {
     assert_eq!(sizing.to_lengths(20.0, 0.0), vec![10.0, 10.0]);
     assert_eq!(sizing.to_lengths(10.0, 0.0), vec![10.0, 10.0]);
 }
+
+#[test]
+fn test_sizing_weighted_remainder() {
+    // A weight of 2.0 gets twice the leftover space of a weight of 1.0.
+    let sizing: Sizing = vec![
+        Size::remainder_weighted(2.0),
+        Size::remainder_weighted(1.0),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(30.0, 0.0), vec![20.0, 10.0]);
+    assert_eq!(sizing.to_lengths(60.0, 0.0), vec![40.0, 20.0]);
+
+    // A weighted remainder that would fall below its minimum is pinned there, and
+    // the rest of the pool is re-divided among the remaining weights.
+    let sizing: Sizing = vec![
+        Size::remainder_weighted(3.0).at_least(35.0),
+        Size::remainder_weighted(1.0),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(40.0, 0.0), vec![35.0, 5.0]);
+
+    // With 3+ remainders, pinning one to its minimum can push the average low enough
+    // to newly violate a *different* cell's minimum — that second cell must also get
+    // pinned (and the pool re-divided again), not just the one caught by the first
+    // pass. The total must still add up to exactly the available length.
+    let sizing: Sizing = vec![
+        Size::remainder().at_least(8.0),
+        Size::remainder().at_least(2.0),
+        Size::remainder(),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(10.0, 0.0), vec![8.0, 2.0, 0.0]);
+}
+
+#[test]
+fn test_sizing_overflow() {
+    // Plenty of slack: the deficit is fully absorbed by shrinking towards `range.min`.
+    let sizing: Sizing = vec![
+        Size::initial(30.0).at_least(10.0),
+        Size::initial(30.0).at_least(10.0),
+    ]
+    .into();
+    let out = sizing.to_lengths_checked(40.0, 0.0);
+    assert_eq!(out.lengths, vec![20.0, 20.0]);
+    assert_eq!(out.overflow, 0.0);
+
+    // No slack at all: every cell is already at its minimum, so the whole deficit is
+    // reported back as overflow instead of shrinking anything further.
+    let sizing: Sizing = vec![Size::exact(30.0), Size::exact(30.0)].into();
+    let out = sizing.to_lengths_checked(40.0, 0.0);
+    assert_eq!(out.lengths, vec![30.0, 30.0]);
+    assert_eq!(out.overflow, 20.0);
+
+    // A plain remainder (no `at_least`) gets nothing when there's a deficit: its own
+    // `range.min` is `0.0`, so it has no floor to fall back on.
+    let sizing: Sizing = vec![Size::initial(30.0).at_least(10.0), Size::remainder()].into();
+    let out = sizing.to_lengths_checked(20.0, 0.0);
+    assert_eq!(out.lengths, vec![20.0, 0.0]);
+    assert_eq!(out.overflow, 0.0);
+
+    // A remainder's own `range.min` is still honored during a deficit instead of
+    // being force-collapsed to zero; since that floor was never part of the original
+    // budget, the space it claims is reported back as `overflow`.
+    let sizing: Sizing = vec![
+        Size::exact(15.0),
+        Size::exact(15.0),
+        Size::remainder().at_least(5.0),
+    ]
+    .into();
+    let out = sizing.to_lengths_checked(20.0, 0.0);
+    assert_eq!(out.lengths, vec![15.0, 15.0, 5.0]);
+    assert_eq!(out.overflow, 15.0);
+}
+
+#[test]
+fn test_sizing_stretch_priority() {
+    // A `Maximize` cell outranks the default `Low` tier of a plain remainder: the
+    // fixed timestamp column stays put, the remainder gets nothing, and the message
+    // column (marked `Maximize`) eats all the slack.
+    let sizing: Sizing = vec![
+        Size::exact(50.0),
+        Size::remainder(),
+        Size::initial(20.0).with_stretch(Stretch::Maximize),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(200.0, 0.0), vec![50.0, 0.0, 150.0]);
+
+    // A remainder explicitly marked `Stretch::None` never grows, even though it would
+    // otherwise be the default catch-all.
+    let sizing: Sizing = vec![
+        Size::remainder().with_stretch(Stretch::None),
+        Size::remainder(),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(100.0, 0.0), vec![0.0, 100.0]);
+
+    // A cell in the growth tier that's capped by `range.max` (here, an exact size) is
+    // pinned there, and the rest of the surplus goes to the other cell in the tier
+    // instead of being dropped on the floor.
+    let sizing: Sizing = vec![
+        Size::exact(50.0).with_stretch(Stretch::Maximize),
+        Size::initial(20.0).with_stretch(Stretch::Maximize),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(200.0, 0.0), vec![50.0, 150.0]);
+
+    // A remainder outside the growth tier still honors its own `at_least` floor
+    // instead of collapsing to zero just because another cell outranks it.
+    let sizing: Sizing = vec![
+        Size::remainder().at_least(30.0),
+        Size::initial(10.0).with_stretch(Stretch::Maximize),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(200.0, 0.0), vec![30.0, 170.0]);
+
+    // With 3+ cells in the growth tier, pinning one to its `range.max` raises the
+    // average enough that a *different* cell can newly exceed its own max — that
+    // second cell must also get pinned (and the pool re-divided again), not just the
+    // one caught by the first pass. The total must still add up to exactly `length`.
+    let sizing: Sizing = vec![
+        Size::initial(0.0).at_most(3.5).with_stretch(Stretch::Maximize),
+        Size::initial(0.0).at_most(2.0).with_stretch(Stretch::Maximize),
+        Size::initial(0.0).with_stretch(Stretch::Maximize),
+    ]
+    .into();
+    assert_eq!(sizing.to_lengths(10.0, 0.0), vec![3.5, 2.0, 4.5]);
+}